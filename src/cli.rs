@@ -0,0 +1,28 @@
+//! Optional constructor for wiring `MLogger` into a `clap`-based binary's `-v`/`-q` verbosity
+//! flags, in the style of `clap-verbosity-flag`. Only compiled in with the `cli` feature enabled.
+use crate::logger::{MLogger, Verbosity};
+
+/// The effective level when neither `-v` nor `-q` is passed.
+const DEFAULT_LEVEL: Verbosity = Verbosity::Warn;
+
+impl MLogger {
+    /// Builds an [`MLogger`] from CLI verbosity occurrence counts, as `clap-verbosity-flag` does:
+    /// each `verbose` occurrence steps one level toward [`Verbosity::Debug`], each `quiet`
+    /// occurrence steps one level toward [`Verbosity::Silent`], starting from
+    /// [`Verbosity::Warn`].
+    ///
+    /// # Param
+    /// - `verbose: u8`: Number of times `-v` was passed.
+    /// - `quiet: u8`: Number of times `-q` was passed.
+    /// - `max_size: usize`: Sets the maximum number of logs until the logger will push out old logs.
+    pub fn from_occurrences(verbose: u8, quiet: u8, max_size: usize) -> MLogger {
+        let mut level = DEFAULT_LEVEL;
+        for _ in 0..verbose {
+            level = level.more();
+        }
+        for _ in 0..quiet {
+            level = level.less();
+        }
+        MLogger::init(level, max_size)
+    }
+}
@@ -1,9 +1,18 @@
 pub mod logger;
+#[cfg(feature = "log")]
+pub mod log_bridge;
+#[cfg(feature = "cli")]
+pub mod cli;
 
 #[cfg(test)]
 mod tests {
+    use crate::logger::ListenerFilter;
     use crate::logger::MLogger;
+    use crate::logger::OverflowPolicy;
     use crate::logger::Verbosity;
+    use std::sync::{Arc, Barrier, Mutex};
+    use std::thread;
+    use std::time::Duration;
 
     #[test]
     fn can_print_logs(){
@@ -31,7 +40,7 @@ mod tests {
         println!("{}",logger.get_size().unwrap());
         let logs = logger.get_log(Verbosity::Debug).unwrap();
         for log in logs {
-            println!("msg: {} , code: {}",log.1,log.0);
+            println!("msg: {} , code: {}",log.message,log.id);
         }
 
         assert_eq!(1,1);
@@ -48,5 +57,180 @@ mod tests {
         logger.print_log().unwrap();
         assert_eq!(1,1);
     }
+    #[test]
+    fn can_filter_logs_by_tag(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        logger.log_tagged("db connection opened", Verbosity::Info, &["db"]).unwrap();
+        logger.log_tagged("socket accepted", Verbosity::Info, &["net"]).unwrap();
+        logger.log("untagged message", Verbosity::Info).unwrap();
+        let db_logs = logger.get_log_by_tag("db", Verbosity::Debug).unwrap();
+        assert_eq!(db_logs.len(), 1);
+        assert_eq!(db_logs[0].message, "db connection opened");
+    }
+    #[test]
+    fn registered_listener_receives_matching_events_only(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        let id = logger
+            .register_listener(ListenerFilter::with_min_verbosity(Verbosity::Warn), move |event| {
+                seen_clone.lock().unwrap().push(event);
+                true
+            })
+            .unwrap();
+        logger.log("this will notify", Verbosity::Error).unwrap();
+        logger.log("this will not notify", Verbosity::Info).unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 1);
+        logger.deregister_listener(id).unwrap();
+        logger.log("this will not notify either", Verbosity::Error).unwrap();
+        assert_eq!(seen.lock().unwrap().len(), 1);
+    }
+    #[test]
+    fn deregistering_a_listener_from_within_its_own_callback_takes_effect(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+        let logger_for_callback = logger.clone();
+        let id_cell: Arc<Mutex<Option<u32>>> = Arc::new(Mutex::new(None));
+        let id_cell_clone = id_cell.clone();
+        let id = logger
+            .register_listener(ListenerFilter::with_min_verbosity(Verbosity::Debug), move |_event| {
+                *calls_clone.lock().unwrap() += 1;
+                if let Some(id) = *id_cell_clone.lock().unwrap() {
+                    logger_for_callback.deregister_listener(id).unwrap();
+                }
+                true
+            })
+            .unwrap();
+        *id_cell.lock().unwrap() = Some(id);
+        logger.log("first", Verbosity::Info).unwrap();
+        logger.log("second", Verbosity::Info).unwrap();
+        assert_eq!(*calls.lock().unwrap(), 1);
+    }
+    #[test]
+    fn listener_callback_can_log_without_deadlocking(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        let logger_for_callback = logger.clone();
+        let triggered = Arc::new(Mutex::new(false));
+        let triggered_clone = triggered.clone();
+        logger
+            .register_listener(ListenerFilter::with_min_verbosity(Verbosity::Debug), move |event| {
+                if event.1 == "trigger" && !*triggered_clone.lock().unwrap() {
+                    *triggered_clone.lock().unwrap() = true;
+                    logger_for_callback
+                        .log("logged from inside a listener callback", Verbosity::Info)
+                        .unwrap();
+                }
+                true
+            })
+            .unwrap();
+        logger.log("trigger", Verbosity::Info).unwrap();
+        assert!(*triggered.lock().unwrap());
+        assert_eq!(logger.get_size().unwrap(), 2);
+    }
+    #[test]
+    fn concurrent_log_calls_both_reach_a_slow_listener(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        logger
+            .register_listener(ListenerFilter::with_min_verbosity(Verbosity::Debug), move |event| {
+                // Hold the per-listener lock long enough to widen the window for a second,
+                // concurrent log() call to race this one for the same listener.
+                thread::sleep(Duration::from_millis(20));
+                seen_clone.lock().unwrap().push(event);
+                true
+            })
+            .unwrap();
+        let barrier = Arc::new(Barrier::new(2));
+        let handles: Vec<_> = ["from thread a", "from thread b"]
+            .into_iter()
+            .map(|message| {
+                let logger = logger.clone();
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    logger.log(message, Verbosity::Info).unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(logger.get_size().unwrap(), 2);
+        assert_eq!(seen.lock().unwrap().len(), 2);
+    }
+    #[test]
+    fn async_logger_processes_queued_logs_on_flush(){
+        let logger = MLogger::init_async(Verbosity::Debug, 100, 4, OverflowPolicy::Block);
+        for i in 0..5 {
+            logger.log(&format!("async message {i}"), Verbosity::Info).unwrap();
+        }
+        logger.flush().unwrap();
+        assert_eq!(logger.get_size().unwrap(), 5);
+    }
+    #[test]
+    fn async_logger_does_not_lose_logs_on_drop_when_queue_is_full(){
+        let logger = MLogger::init_async(Verbosity::Debug, 100, 3, OverflowPolicy::DropOldest);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_clone = seen.clone();
+        logger
+            .register_listener(ListenerFilter::with_min_verbosity(Verbosity::Debug), move |event| {
+                seen_clone.lock().unwrap().push(event);
+                true
+            })
+            .unwrap();
+        logger.log("one", Verbosity::Info).unwrap();
+        logger.log("two", Verbosity::Info).unwrap();
+        logger.log("three", Verbosity::Info).unwrap();
+        // Drop joins the worker thread synchronously, so by the time this returns the queue
+        // (including the Shutdown command pushed here) has been fully drained.
+        drop(logger);
+        assert_eq!(seen.lock().unwrap().len(), 3);
+    }
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_facade_bridge_routes_into_the_pool_with_target_and_module_path(){
+        let logger = MLogger::init(Verbosity::Debug, 100);
+        logger.clone().install().unwrap();
+        log::info!("hello through the log facade");
+        assert_eq!(logger.get_size().unwrap(), 1);
+        let entry = logger.get_entry(0).unwrap();
+        assert_eq!(entry.message, "hello through the log facade");
+        assert_eq!(entry.verbosity, Verbosity::Info);
+        assert_eq!(entry.target.as_deref(), Some(module_path!()));
+        assert_eq!(entry.module_path.as_deref(), Some(module_path!()));
+    }
+    #[test]
+    fn can_change_global_level_at_runtime(){
+        let logger = MLogger::init(Verbosity::Error, 100);
+        assert_eq!(logger.verbosity(), Verbosity::Error);
+        logger.set_global_level(Verbosity::Debug).unwrap();
+        assert_eq!(logger.verbosity(), Verbosity::Debug);
+    }
+    #[test]
+    fn verbosity_steps_saturate(){
+        assert_eq!(Verbosity::Debug.more(), Verbosity::Debug);
+        assert_eq!(Verbosity::Silent.less(), Verbosity::Silent);
+        assert_eq!(Verbosity::Warn.more(), Verbosity::Info);
+        assert_eq!(Verbosity::Warn.less(), Verbosity::Error);
+    }
+    #[cfg(feature = "cli")]
+    #[test]
+    fn from_occurrences_steps_from_the_default_level(){
+        let logger = MLogger::from_occurrences(2, 0, 50);
+        assert_eq!(logger.verbosity(), Verbosity::Debug);
+        let quiet_logger = MLogger::from_occurrences(0, 5, 50);
+        assert_eq!(quiet_logger.verbosity(), Verbosity::Silent);
+    }
+    #[test]
+    fn mlog_macro_skips_suppressed_messages(){
+        let logger = MLogger::init(Verbosity::Warn, 100);
+        let x = 1;
+        let y = 2;
+        crate::mlog!(logger, Verbosity::Info, "x={} y={}", x, y).unwrap();
+        crate::mlog!(logger, Verbosity::Error, "x={} y={}", x, y).unwrap();
+        assert_eq!(logger.get_size().unwrap(), 1);
+    }
 
 }
@@ -0,0 +1,61 @@
+//! Optional bridge so `MLogger` can serve as the global backend for the [`log`] facade crate.
+//! Only compiled in with the `log` feature enabled.
+use crate::logger::{MLogger, Verbosity};
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+impl From<Level> for Verbosity {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Error => Verbosity::Error,
+            Level::Warn => Verbosity::Warn,
+            Level::Info => Verbosity::Info,
+            Level::Debug | Level::Trace => Verbosity::Debug,
+        }
+    }
+}
+
+fn to_level_filter(verbosity: Verbosity) -> LevelFilter {
+    match verbosity {
+        Verbosity::Silent => LevelFilter::Off,
+        Verbosity::Error => LevelFilter::Error,
+        Verbosity::Warn => LevelFilter::Warn,
+        Verbosity::Info => LevelFilter::Info,
+        // `Verbosity` has no `Trace` of its own; `Debug` is our most verbose level and accepts
+        // `log::Level::Trace` too (see the `From<Level>` impl above), so let everything through.
+        Verbosity::Debug => LevelFilter::Trace,
+    }
+}
+
+impl Log for MLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.accepts_for_target(Verbosity::from(metadata.level()), Some(metadata.target()))
+    }
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let verbosity = Verbosity::from(record.level());
+        let message = record.args().to_string();
+        let target = Some(record.target().to_string());
+        let module_path = record.module_path().map(str::to_string);
+        let _ = self.log_internal(message, verbosity, target, module_path, Vec::new());
+    }
+    fn flush(&self) {
+        let _ = MLogger::flush(self);
+    }
+}
+
+impl MLogger {
+    /// Installs `self` as the process-wide backend for the `log` facade, wrapping it in a
+    /// `'static` reference so `info!`/`error!` etc. throughout the binary end up in this
+    /// logger's pool.
+    ///
+    /// Returns `Err` if a logger has already been installed.
+    pub fn install(self) -> Result<(), SetLoggerError> {
+        let max_level = to_level_filter(self.verbosity());
+        let installed: &'static MLogger = Box::leak(Box::new(self));
+        log::set_logger(installed)?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
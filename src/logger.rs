@@ -1,9 +1,243 @@
 use std::{
-    collections::VecDeque,
+    cell::RefCell,
+    collections::{HashMap, HashSet, VecDeque},
     fmt::Display,
-    sync::{Arc, Mutex},
+    sync::{mpsc, Arc, Condvar, Mutex},
+    thread::{self, JoinHandle},
 };
 
+/// A single log entry as handed to a registered listener: `(id, message, verbosity)`.
+pub type LogEvent = (u32, String, Verbosity);
+
+/// `ListenerFilter` decides whether a given [`LogEvent`] is relevant to a listener.
+///
+/// # Parameters
+/// - `min_verbosity`: Only events at this verbosity or stricter (lower) are delivered.
+/// - `contains_substring`: If set, only messages containing this substring are delivered.
+/// - `id_range`: If set, only events whose id falls within `(start, end)` (inclusive) are delivered.
+#[derive(Debug, Clone, Default)]
+pub struct ListenerFilter {
+    pub min_verbosity: Verbosity,
+    pub contains_substring: Option<String>,
+    pub id_range: Option<(u32, u32)>,
+}
+impl ListenerFilter {
+    /// Creates a `ListenerFilter` that only restricts on verbosity.
+    pub fn with_min_verbosity(min_verbosity: Verbosity) -> ListenerFilter {
+        ListenerFilter {
+            min_verbosity,
+            contains_substring: None,
+            id_range: None,
+        }
+    }
+    fn matches(&self, event: &LogEvent) -> bool {
+        if event.2 > self.min_verbosity {
+            return false;
+        }
+        if let Some(substring) = &self.contains_substring {
+            if !event.1.contains(substring.as_str()) {
+                return false;
+            }
+        }
+        if let Some((start, end)) = self.id_range {
+            if event.0 < start || event.0 > end {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// A registered listener callback; return `false` to signal it is stale and should be dropped.
+type ListenerCallback = Box<dyn FnMut(LogEvent) -> bool + Send>;
+
+struct ListenerEntry {
+    id: u32,
+    filter: ListenerFilter,
+    /// Own lock per listener rather than sharing the `listeners` lock, so two [`notify_listeners`]
+    /// calls racing for the *same* listener serialize (the second blocks and still gets delivered)
+    /// instead of one silently skipping it.
+    callback: Arc<Mutex<ListenerCallback>>,
+}
+
+thread_local! {
+    /// Listener ids whose callback is currently running on this thread. Lets a callback that logs
+    /// a message matching its own filter skip re-entering itself instead of blocking forever on
+    /// its own per-listener lock; a call from another thread is not affected and still blocks
+    /// until the in-flight invocation finishes, so it is never dropped.
+    static IN_FLIGHT_LISTENERS: RefCell<HashSet<u32>> = RefCell::new(HashSet::new());
+}
+
+/// Clears a listener id out of [`IN_FLIGHT_LISTENERS`] when it goes out of scope, including via
+/// an early `continue` or a panic unwinding through the callback.
+struct InFlightGuard(u32);
+impl Drop for InFlightGuard {
+    fn drop(&mut self) {
+        IN_FLIGHT_LISTENERS.with(|ids| {
+            ids.borrow_mut().remove(&self.0);
+        });
+    }
+}
+
+/// Evaluates every registered listener's [`ListenerFilter`] against `event` and invokes the
+/// matching ones synchronously. A listener whose callback returns `false` is considered stale
+/// and removed. Lives outside `impl MLogger` so the background worker spawned by
+/// [`MLogger::init_async`] can notify listeners without holding a reference to the logger itself.
+///
+/// The `listeners` lock is only held long enough to collect which entries match and clone their
+/// (already `Arc`-shared) callback handles — never while a callback runs. Each matching callback
+/// is then invoked through its own per-listener lock, so two threads racing to notify the same
+/// listener serialize instead of one of them dropping the event (the bug a shared checkout scheme
+/// used to have): whichever thread gets there second simply blocks until the first finishes, then
+/// still delivers its own event. A callback that logs through the same (non-async) `MLogger` and
+/// matches its own filter would otherwise re-enter this function and deadlock trying to re-lock a
+/// `Mutex` its own thread already holds; [`IN_FLIGHT_LISTENERS`] detects that same-thread
+/// reentrancy and skips the nested call instead.
+fn notify_listeners(listeners: &Arc<Mutex<Vec<ListenerEntry>>>, entry: &LogEntry) {
+    let event: LogEvent = (entry.id, entry.message.clone(), entry.verbosity);
+    let matched: Vec<(u32, Arc<Mutex<ListenerCallback>>)> = match listeners.lock() {
+        Ok(listeners) => listeners
+            .iter()
+            .filter(|entry| entry.filter.matches(&event))
+            .map(|entry| (entry.id, entry.callback.clone()))
+            .collect(),
+        Err(_) => return,
+    };
+    let mut stale_ids = Vec::new();
+    for (id, callback) in matched {
+        let already_in_flight_on_this_thread =
+            IN_FLIGHT_LISTENERS.with(|ids| !ids.borrow_mut().insert(id));
+        if already_in_flight_on_this_thread {
+            continue;
+        }
+        let _guard = InFlightGuard(id);
+        // A callback that panics mid-invocation poisons its own lock permanently; treat that the
+        // same as a stale listener (drop it) rather than leaving a dead entry that silently stops
+        // receiving events forever.
+        let keep = match callback.lock() {
+            Ok(mut callback) => callback(event.clone()),
+            Err(_) => false,
+        };
+        if !keep {
+            stale_ids.push(id);
+        }
+    }
+    if !stale_ids.is_empty() {
+        if let Ok(mut listeners) = listeners.lock() {
+            listeners.retain(|entry| !stale_ids.contains(&entry.id));
+        }
+    }
+}
+
+/// Resolves the print threshold for an entry: a per-target override if one is set for `target`,
+/// otherwise the global threshold.
+fn effective_threshold(
+    global: &Arc<Mutex<Verbosity>>,
+    target_levels: &Arc<Mutex<HashMap<String, Verbosity>>>,
+    target: Option<&str>,
+) -> Verbosity {
+    let global_level = match global.lock() {
+        Ok(level) => *level,
+        Err(_) => return Verbosity::Silent,
+    };
+    let target = match target {
+        Some(target) => target,
+        None => return global_level,
+    };
+    match target_levels.lock() {
+        Ok(levels) => levels.get(target).copied().unwrap_or(global_level),
+        Err(_) => global_level,
+    }
+}
+
+/// Selects what happens to [`MLogger::log`] calls made while an async logger's channel is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Block the caller until the worker thread makes room.
+    Block,
+    /// Evict the oldest pending (not-yet-processed) log to make room for the new one.
+    DropOldest,
+}
+
+enum WorkerCommand {
+    Log(PendingLog),
+    Flush(mpsc::Sender<()>),
+    Shutdown,
+}
+
+/// A bounded MPMC queue of [`WorkerCommand`]s, backing [`MLogger::init_async`].
+///
+/// Implemented with a `Mutex`-guarded `VecDeque` and a pair of `Condvar`s rather than
+/// `std::sync::mpsc`, since [`OverflowPolicy::DropOldest`] needs to evict an already-buffered
+/// command, which a channel's sender half cannot do.
+///
+/// `capacity` only bounds [`WorkerCommand::Log`] commands, pushed via [`WorkerQueue::push`].
+/// [`WorkerCommand::Flush`]/[`WorkerCommand::Shutdown`] are control commands pushed via
+/// [`WorkerQueue::push_control`], which bypasses the capacity check and eviction policy entirely
+/// — otherwise, with [`OverflowPolicy::DropOldest`], enqueuing a `Shutdown` on drop could evict an
+/// already-buffered, not-yet-processed log purely to make room for itself.
+struct WorkerQueue {
+    queue: Mutex<VecDeque<WorkerCommand>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    not_empty: Condvar,
+    not_full: Condvar,
+}
+impl WorkerQueue {
+    fn new(capacity: usize, policy: OverflowPolicy) -> WorkerQueue {
+        WorkerQueue {
+            queue: Mutex::new(VecDeque::new()),
+            capacity: capacity.max(1),
+            policy,
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+        }
+    }
+    fn push(&self, command: WorkerCommand) {
+        let mut queue = match self.queue.lock() {
+            Ok(queue) => queue,
+            Err(_) => return,
+        };
+        if queue.len() >= self.capacity {
+            match self.policy {
+                OverflowPolicy::Block => {
+                    while queue.len() >= self.capacity {
+                        queue = match self.not_full.wait(queue) {
+                            Ok(queue) => queue,
+                            Err(_) => return,
+                        };
+                    }
+                }
+                OverflowPolicy::DropOldest => {
+                    queue.pop_front();
+                }
+            }
+        }
+        queue.push_back(command);
+        self.not_empty.notify_one();
+    }
+    /// Enqueues a control command (`Flush`/`Shutdown`) without going through the capacity check
+    /// or [`OverflowPolicy`] at all, so it can never cause a real, not-yet-processed log to be
+    /// evicted just to make room for itself.
+    fn push_control(&self, command: WorkerCommand) {
+        let mut queue = match self.queue.lock() {
+            Ok(queue) => queue,
+            Err(_) => return,
+        };
+        queue.push_back(command);
+        self.not_empty.notify_one();
+    }
+    fn pop(&self) -> Option<WorkerCommand> {
+        let mut queue = self.queue.lock().ok()?;
+        while queue.is_empty() {
+            queue = self.not_empty.wait(queue).ok()?;
+        }
+        let command = queue.pop_front();
+        self.not_full.notify_one();
+        command
+    }
+}
+
 /// `Verbosity` is the enum that declares the scope of each log.   
 /// Don't use [`Verbosity::Silent`] as a log condition.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -14,6 +248,34 @@ pub enum Verbosity {
     Info,
     Debug,
 }
+impl Default for Verbosity {
+    /// Defaults to [`Verbosity::Debug`] so a default-constructed filter accepts everything.
+    fn default() -> Self {
+        Verbosity::Debug
+    }
+}
+impl Verbosity {
+    /// Steps one level more verbose, saturating at [`Verbosity::Debug`].
+    pub fn more(self) -> Verbosity {
+        match self {
+            Verbosity::Silent => Verbosity::Error,
+            Verbosity::Error => Verbosity::Warn,
+            Verbosity::Warn => Verbosity::Info,
+            Verbosity::Info => Verbosity::Debug,
+            Verbosity::Debug => Verbosity::Debug,
+        }
+    }
+    /// Steps one level less verbose, saturating at [`Verbosity::Silent`].
+    pub fn less(self) -> Verbosity {
+        match self {
+            Verbosity::Silent => Verbosity::Silent,
+            Verbosity::Error => Verbosity::Silent,
+            Verbosity::Warn => Verbosity::Error,
+            Verbosity::Info => Verbosity::Warn,
+            Verbosity::Debug => Verbosity::Info,
+        }
+    }
+}
 impl Display for Verbosity {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -25,37 +287,83 @@ impl Display for Verbosity {
         }
     }
 }
+/// A single stored log, with optional structured metadata for subsystem-level filtering.
+///
+/// # Fields
+/// - `id: u32`: The counted ID, assigned automatically.
+/// - `message: String`: The log message itself.
+/// - `verbosity: Verbosity`: Sets the [`Verbosity`] level of the log.
+/// - `target: Option<String>`: Mirrors the `target` concept from the `log` crate; `None` for
+///   entries logged through the plain [`MLogger::log`].
+/// - `module_path: Option<String>`: Mirrors the `log` crate's `Record::module_path()`; only ever
+///   populated through the `log` bridge (see `log_bridge`), `None` otherwise.
+/// - `tags: Vec<String>`: Subsystem tags attached via [`MLogger::log_tagged`], e.g. `"net"`, `"db"`.
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub id: u32,
+    pub message: String,
+    pub verbosity: Verbosity,
+    pub target: Option<String>,
+    pub module_path: Option<String>,
+    pub tags: Vec<String>,
+}
+
+/// The not-yet-assigned-an-id payload of a log call; carries everything [`LogEntry`] needs
+/// besides the `id`, which is only known once a slot is taken under the counter lock.
+struct PendingLog {
+    message: String,
+    verbosity: Verbosity,
+    target: Option<String>,
+    module_path: Option<String>,
+    tags: Vec<String>,
+}
+
 /// `MLogger` is a struct to control the logging logic and hold the logs.
 ///
 /// # Parameters
-/// - `verbosity`: Controls the logger print logic; it will print only if the verbosity of the log is within the threshold.   
-///   The threshold is defined by the Enum [`Verbosity`].   
-/// - `max_size: usize`: Controls the maximum number of logs that can exist in the instance; will push out old logs above that limit.   
-/// - `pool: VecDeque<(u32, String, Verbosity)>`: Mutex-controlled double-ended queue that holds all the logs.   
+/// - `verbosity`: Controls the logger print logic; it will print only if the verbosity of the log is within the threshold.
+///   The threshold is defined by the Enum [`Verbosity`]. Mutex-controlled so it can be changed at
+///   runtime via [`MLogger::set_global_level`].
+/// - `max_size: usize`: Controls the maximum number of logs that can exist in the instance; will push out old logs above that limit.
+/// - `pool: VecDeque<LogEntry>`: Mutex-controlled double-ended queue that holds all the logs.
 /// - `counter: u32`: Counts the log ID; each log in a session gets a counted ID, so index 1 does not imply id == 1.
+/// - `listeners`: Mutex-controlled list of subscribers registered via [`MLogger::register_listener`].
+/// - `target_levels`: Per-target verbosity overrides set via [`MLogger::set_target_level`].
 ///
 /// # Log Entry
-/// A log entry is a tuple of `(u32, String, Verbosity)`, where:   
-/// - `u32`: The counted ID, assigned automatically.   
-/// - `String`: The log message itself.   
-/// - `Verbosity`: Sets the [`Verbosity`] level of the log.
+/// See [`LogEntry`].
 ///
 /// # Initialization
 /// To get a `MLogger` instance, call [`MLogger::init_default()`] or [`MLogger::init()`] to control the verbosity level and maximum pool size.
+#[derive(Clone)]
 pub struct MLogger {
-    verbosity: Verbosity,
+    verbosity: Arc<Mutex<Verbosity>>,
     max_size: usize,
-    pool: Arc<Mutex<VecDeque<(u32, String, Verbosity)>>>,
+    pool: Arc<Mutex<VecDeque<LogEntry>>>,
     counter: Arc<Mutex<u32>>,
+    listeners: Arc<Mutex<Vec<ListenerEntry>>>,
+    listener_counter: Arc<Mutex<u32>>,
+    target_levels: Arc<Mutex<HashMap<String, Verbosity>>>,
+    /// `Some` for loggers created via [`MLogger::init_async`]; `log()` pushes onto this queue
+    /// instead of locking `pool`/`counter` directly.
+    queue: Option<Arc<WorkerQueue>>,
+    /// Shared so every clone of an async `MLogger` can tell, via `Arc::strong_count`, whether it
+    /// is the last handle responsible for shutting the worker thread down.
+    worker: Option<Arc<Mutex<Option<JoinHandle<()>>>>>,
 }
 impl MLogger {
     /// This method will create a [`MLogger`] instance by its default values `1000` for the pool and [`Verbosity::Debug`] for verbosity.
     pub fn init_default() -> MLogger {
         return MLogger {
-            verbosity: Verbosity::Debug,
+            verbosity: Arc::new(Mutex::new(Verbosity::Debug)),
             max_size: 1000,
             pool: Arc::new(Mutex::new(VecDeque::new())),
             counter: Arc::new(Mutex::new(0)),
+            listeners: Arc::new(Mutex::new(Vec::new())),
+            listener_counter: Arc::new(Mutex::new(0)),
+            target_levels: Arc::new(Mutex::new(HashMap::new())),
+            queue: None,
+            worker: None,
         };
     }
     /// Creates a [`MLogger`] instance, allowing control over the pool size and verbosity level.
@@ -65,12 +373,103 @@ impl MLogger {
     /// - `max_size: usize`: Sets the maximum number of logs until the logger will push out old logs.
     pub fn init(verbosity: Verbosity, max_size: usize) -> MLogger {
         return MLogger {
-            verbosity: verbosity,
+            verbosity: Arc::new(Mutex::new(verbosity)),
             max_size: max_size,
             pool: Arc::new(Mutex::new(VecDeque::new())),
             counter: Arc::new(Mutex::new(0)),
+            listeners: Arc::new(Mutex::new(Vec::new())),
+            listener_counter: Arc::new(Mutex::new(0)),
+            target_levels: Arc::new(Mutex::new(HashMap::new())),
+            queue: None,
+            worker: None,
         };
     }
+    /// Creates an [`MLogger`] handle that offloads the actual pool/counter writes and
+    /// `println!`ing to a dedicated worker thread, following the fast-logger pattern of keeping
+    /// producer threads off the hot path. [`MLogger::log`] merely pushes `(message, verbosity)`
+    /// onto a bounded queue and returns immediately. The returned `MLogger` is cheap to `clone`;
+    /// every clone shares the same queue and worker, and the worker is joined once the last
+    /// clone is dropped (or [`MLogger::flush`] is awaited explicitly).
+    ///
+    /// # Param
+    /// - `verbosity: Verbosity`: Sets the worker's verbosity print threshold.
+    /// - `max_size: usize`: Sets the maximum number of logs until the worker pushes out old logs.
+    /// - `channel_bound: usize`: Maximum number of not-yet-processed log commands to buffer.
+    /// - `overflow: OverflowPolicy`: What to do when the queue is full and a new log arrives.
+    pub fn init_async(
+        verbosity: Verbosity,
+        max_size: usize,
+        channel_bound: usize,
+        overflow: OverflowPolicy,
+    ) -> MLogger {
+        let pool = Arc::new(Mutex::new(VecDeque::new()));
+        let counter = Arc::new(Mutex::new(0));
+        let listeners = Arc::new(Mutex::new(Vec::new()));
+        let verbosity = Arc::new(Mutex::new(verbosity));
+        let target_levels = Arc::new(Mutex::new(HashMap::new()));
+        let queue = Arc::new(WorkerQueue::new(channel_bound, overflow));
+
+        let worker_pool = pool.clone();
+        let worker_counter = counter.clone();
+        let worker_listeners = listeners.clone();
+        let worker_verbosity = verbosity.clone();
+        let worker_target_levels = target_levels.clone();
+        let worker_queue = queue.clone();
+        let handle = thread::spawn(move || loop {
+            let command = match worker_queue.pop() {
+                Some(command) => command,
+                None => return,
+            };
+            match command {
+                WorkerCommand::Log(pending) => {
+                    let mut pool = match worker_pool.lock() {
+                        Ok(pool) => pool,
+                        Err(_) => return,
+                    };
+                    let mut counter = match worker_counter.lock() {
+                        Ok(counter) => counter,
+                        Err(_) => return,
+                    };
+                    let entry = LogEntry {
+                        id: *counter,
+                        message: pending.message,
+                        verbosity: pending.verbosity,
+                        target: pending.target,
+                        module_path: pending.module_path,
+                        tags: pending.tags,
+                    };
+                    let threshold = effective_threshold(&worker_verbosity, &worker_target_levels, entry.target.as_deref());
+                    if entry.verbosity <= threshold {
+                        println!("{}", entry.message);
+                    }
+                    pool.push_front(entry.clone());
+                    if pool.len() > max_size {
+                        pool.pop_back();
+                    }
+                    *counter += 1;
+                    drop(pool);
+                    drop(counter);
+                    notify_listeners(&worker_listeners, &entry);
+                }
+                WorkerCommand::Flush(ack) => {
+                    let _ = ack.send(());
+                }
+                WorkerCommand::Shutdown => return,
+            }
+        });
+
+        MLogger {
+            verbosity,
+            max_size,
+            pool,
+            counter,
+            listeners,
+            listener_counter: Arc::new(Mutex::new(0)),
+            target_levels,
+            queue: Some(queue),
+            worker: Some(Arc::new(Mutex::new(Some(handle)))),
+        }
+    }
     /// Inserts a log into the MLogger; it will print if the verbosity predicate matches.
     ///
     /// # Param
@@ -79,6 +478,84 @@ impl MLogger {
     ///
     /// Returns a `Result` with an error message as a `String` or `()` on success.
     pub fn log(&self, log: &str, verbosity: Verbosity) -> Result<(), String> {
+        self.log_internal(log.to_string(), verbosity, None, None, Vec::new())
+    }
+    /// Inserts a log tagged with one or more subsystem tags (e.g. `"net"`, `"db"`), retrievable
+    /// later via [`MLogger::get_log_by_tag`]/[`MLogger::print_log_tagged`].
+    ///
+    /// # Param
+    /// - `log: &str`: The message to be logged.
+    /// - `verbosity: Verbosity`: The message verbosity level.
+    /// - `tags: &[&str]`: Subsystem tags to attach to the entry.
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn log_tagged(&self, log: &str, verbosity: Verbosity, tags: &[&str]) -> Result<(), String> {
+        let tags = tags.iter().map(|tag| tag.to_string()).collect();
+        self.log_internal(log.to_string(), verbosity, None, None, tags)
+    }
+    /// Inserts a log carrying an explicit `target` (the same concept the `log` crate bridge
+    /// populates via `Record::target()`), letting [`MLogger::set_target_level`] filter a
+    /// subsystem without enabling the `log` feature.
+    ///
+    /// # Param
+    /// - `log: &str`: The message to be logged.
+    /// - `verbosity: Verbosity`: The message verbosity level.
+    /// - `target: &str`: The target to attach to the entry, matching [`LogEntry::target`].
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn log_with_target(&self, log: &str, verbosity: Verbosity, target: &str) -> Result<(), String> {
+        self.log_internal(log.to_string(), verbosity, Some(target.to_string()), None, Vec::new())
+    }
+    /// Returns whether an entry at `verbosity` would currently be accepted (stored/printed)
+    /// against the global threshold, without materializing a message. Used by [`MLogger::log_fmt`]
+    /// and the [`crate::mlog`] macro to skip formatting work for messages that would be
+    /// suppressed anyway.
+    pub fn accepts(&self, verbosity: Verbosity) -> bool {
+        verbosity <= effective_threshold(&self.verbosity, &self.target_levels, None)
+    }
+    /// Same check as [`MLogger::accepts`], but against a specific target's override if one is
+    /// set. Used by the `log` crate bridge (see `log_bridge`) so `Log::enabled`/`Log::log` respect
+    /// [`MLogger::set_target_level`] instead of only the global threshold.
+    pub(crate) fn accepts_for_target(&self, verbosity: Verbosity, target: Option<&str>) -> bool {
+        verbosity <= effective_threshold(&self.verbosity, &self.target_levels, target)
+    }
+    /// Logs a pre-built [`std::fmt::Arguments`], only materializing the `String` if `verbosity`
+    /// passes the threshold check. `format_args!` itself is free (it just borrows its arguments),
+    /// so building the message eagerly the way [`MLogger::log`] does is the only real cost this
+    /// avoids; prefer the [`crate::mlog`] macro over calling this directly.
+    ///
+    /// Unlike [`MLogger::log`]/[`MLogger::log_tagged`], which always push onto the pool and
+    /// notify listeners regardless of verbosity, a suppressed `log_fmt` call is a complete no-op:
+    /// nothing is stored and no listener is notified. That's the whole point of deferring the
+    /// format — there would be nothing to defer if a suppressed entry still had to be built.
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn log_fmt(&self, args: std::fmt::Arguments, verbosity: Verbosity) -> Result<(), String> {
+        if !self.accepts(verbosity) {
+            return Ok(());
+        }
+        self.log_internal(args.to_string(), verbosity, None, None, Vec::new())
+    }
+    /// Shared insertion path for [`MLogger::log`]/[`MLogger::log_tagged`] and the `log` crate
+    /// bridge (see `log_bridge`), which additionally supplies a `target` and `module_path`.
+    pub(crate) fn log_internal(
+        &self,
+        message: String,
+        verbosity: Verbosity,
+        target: Option<String>,
+        module_path: Option<String>,
+        tags: Vec<String>,
+    ) -> Result<(), String> {
+        if let Some(queue) = &self.queue {
+            queue.push(WorkerCommand::Log(PendingLog {
+                message,
+                verbosity,
+                target,
+                module_path,
+                tags,
+            }));
+            return Ok(());
+        }
         let mut pool = self
             .pool
             .lock()
@@ -87,15 +564,82 @@ impl MLogger {
             .counter
             .lock()
             .map_err(|_| "counter lock failed!".to_string())?;
-        let log_entry = (*counter, log.to_string(), verbosity);
-        if log_entry.2 <= self.verbosity {
-            println!("{}", log_entry.1.clone());
+        let entry = LogEntry {
+            id: *counter,
+            message,
+            verbosity,
+            target,
+            module_path,
+            tags,
+        };
+        let threshold = effective_threshold(&self.verbosity, &self.target_levels, entry.target.as_deref());
+        if entry.verbosity <= threshold {
+            println!("{}", entry.message);
         }
-        pool.push_front(log_entry);
+        pool.push_front(entry.clone());
         if pool.len() > self.max_size {
             pool.pop_back();
         }
         *counter += 1;
+        drop(pool);
+        drop(counter);
+        notify_listeners(&self.listeners, &entry);
+        Ok(())
+    }
+    /// Blocks until the background worker spawned by [`MLogger::init_async`] has drained every
+    /// command queued before this call. No-op for loggers created via [`MLogger::init`]/
+    /// [`MLogger::init_default`].
+    pub fn flush(&self) -> Result<(), String> {
+        let queue = match &self.queue {
+            Some(queue) => queue,
+            None => return Ok(()),
+        };
+        let (ack_sender, ack_receiver) = mpsc::channel();
+        queue.push_control(WorkerCommand::Flush(ack_sender));
+        ack_receiver
+            .recv()
+            .map_err(|_| "worker thread shut down before flush completed".to_string())
+    }
+    /// Registers a listener that will be invoked synchronously whenever a [`LogEvent`] matching
+    /// `filter` is accepted by [`MLogger::log`].
+    ///
+    /// # Param
+    /// - `filter: ListenerFilter`: Restricts which events are delivered to `callback`.
+    /// - `callback`: Invoked with a clone of the matching [`LogEvent`]; return `false` to signal
+    ///   the listener is stale so it is dropped automatically.
+    ///
+    /// Returns the listener id, which can later be passed to [`MLogger::deregister_listener`].
+    pub fn register_listener(
+        &self,
+        filter: ListenerFilter,
+        callback: impl FnMut(LogEvent) -> bool + Send + 'static,
+    ) -> Result<u32, String> {
+        let mut listener_counter = self
+            .listener_counter
+            .lock()
+            .map_err(|_| "listener counter lock failed!".to_string())?;
+        let id = *listener_counter;
+        *listener_counter += 1;
+        let mut listeners = self
+            .listeners
+            .lock()
+            .map_err(|_| "listeners lock failed!".to_string())?;
+        listeners.push(ListenerEntry {
+            id,
+            filter,
+            callback: Arc::new(Mutex::new(Box::new(callback))),
+        });
+        Ok(id)
+    }
+    /// Removes a listener previously returned by [`MLogger::register_listener`].
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn deregister_listener(&self, id: u32) -> Result<(), String> {
+        let mut listeners = self
+            .listeners
+            .lock()
+            .map_err(|_| "listeners lock failed!".to_string())?;
+        listeners.retain(|entry| entry.id != id);
         Ok(())
     }
     /// Retrieves an entry from the logger.   
@@ -104,8 +648,8 @@ impl MLogger {
     /// # Param
     /// - `index: usize`: The log index in the pool.
     ///
-    /// Returns a `Result` with an error message as a `String` or the log entry tuple on success.
-    pub fn get_entry(&self, index: usize) -> Result<(u32, String, Verbosity), String> {
+    /// Returns a `Result` with an error message as a `String` or the [`LogEntry`] on success.
+    pub fn get_entry(&self, index: usize) -> Result<LogEntry, String> {
         let pool = self
             .pool
             .lock()
@@ -114,6 +658,53 @@ impl MLogger {
             .cloned()
             .ok_or_else(|| "index out of bounds".to_string())
     }
+    /// Retrieves the logger's current global verbosity threshold.
+    pub fn verbosity(&self) -> Verbosity {
+        match self.verbosity.lock() {
+            Ok(verbosity) => *verbosity,
+            Err(_) => Verbosity::Silent,
+        }
+    }
+    /// Changes the global verbosity threshold at runtime, without recompiling or recreating the
+    /// logger. Entries for a `target` with an override set via [`MLogger::set_target_level`] keep
+    /// using that override instead.
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn set_global_level(&self, level: Verbosity) -> Result<(), String> {
+        let mut verbosity = self
+            .verbosity
+            .lock()
+            .map_err(|_| "verbosity lock failed!".to_string())?;
+        *verbosity = level;
+        Ok(())
+    }
+    /// Sets (or replaces) a per-target verbosity override, letting a single noisy subsystem be
+    /// silenced or promoted without touching the global threshold. An override only ever matters
+    /// for entries carrying `Some(target)`: use [`MLogger::log_with_target`] to attach one without
+    /// enabling the `log` feature, or the `log` crate bridge (see `log_bridge`) if it is enabled.
+    /// Plain [`MLogger::log`]/[`MLogger::log_tagged`] entries always carry `target: None` and so
+    /// are never affected by an override, regardless of their `tags`.
+    ///
+    /// Note for `log` crate bridge users: the `log` facade filters at the call site against a
+    /// single process-wide [`log::max_level`], set once in [`MLogger::install`] from the global
+    /// threshold. Promoting a target above that level here will not surface records the `log`
+    /// crate already dropped before reaching us; only demoting a target below the global level is
+    /// fully effective through the bridge.
+    ///
+    /// # Param
+    /// - `target: &str`: The target to override, matching [`LogEntry::target`] (e.g. as set by
+    ///   the `log` crate bridge).
+    /// - `level: Verbosity`: The threshold to use for entries carrying that target.
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn set_target_level(&self, target: &str, level: Verbosity) -> Result<(), String> {
+        let mut target_levels = self
+            .target_levels
+            .lock()
+            .map_err(|_| "target levels lock failed!".to_string())?;
+        target_levels.insert(target.to_string(), level);
+        Ok(())
+    }
     /// Retrieves the current size of the pool.
     ///
     /// Returns a `Result` with an error message as a `String` or the size (`usize`) on success.
@@ -131,13 +722,36 @@ impl MLogger {
     /// - `filter: Verbosity`: The predicate to limit the scope of the logs.
     ///
     /// Returns a `Result` with an error message as a `String` or all the log entries that match the predicate on success.
-    pub fn get_log(&self, filter: Verbosity) -> Result<Vec<(u32, String, Verbosity)>, String> {
+    pub fn get_log(&self, filter: Verbosity) -> Result<Vec<LogEntry>, String> {
+        let pool = self
+            .pool
+            .lock()
+            .map_err(|_| "pool lock failed!".to_string())?;
+        let filtered_logs: Vec<LogEntry> = pool
+            .iter()
+            .filter(|log| log.verbosity <= filter)
+            .cloned()
+            .collect();
+        Ok(filtered_logs)
+    }
+    /// Retrieves all entries tagged with `tag` (see [`MLogger::log_tagged`]) that also satisfy a
+    /// verbosity filter.
+    ///
+    /// # Param
+    /// - `tag: &str`: The tag an entry must carry to be included.
+    /// - `filter: Verbosity`: The predicate to limit the scope of the logs.
+    ///
+    /// Returns a `Result` with an error message as a `String` or the matching entries on success.
+    pub fn get_log_by_tag(&self, tag: &str, filter: Verbosity) -> Result<Vec<LogEntry>, String> {
         let pool = self
             .pool
             .lock()
             .map_err(|_| "pool lock failed!".to_string())?;
-        let filtered_logs: Vec<(u32, String, Verbosity)> =
-            pool.iter().filter(|log| log.2 <= filter).cloned().collect();
+        let filtered_logs: Vec<LogEntry> = pool
+            .iter()
+            .filter(|log| log.verbosity <= filter && log.tags.iter().any(|t| t == tag))
+            .cloned()
+            .collect();
         Ok(filtered_logs)
     }
     /// Retrieves a slice from the logs.
@@ -154,14 +768,14 @@ impl MLogger {
         start_index: usize,
         end_index: usize,
         filter: Verbosity,
-    ) -> Result<Vec<(u32, String, Verbosity)>, String> {
+    ) -> Result<Vec<LogEntry>, String> {
         let pool = self
             .pool
             .lock()
             .map_err(|_| "pool lock failed!".to_string())?;
         let sub_pool = pool
             .range(start_index..end_index)
-            .filter(|log| log.2 <= filter)
+            .filter(|log| log.verbosity <= filter)
             .cloned()
             .collect();
         Ok(sub_pool)
@@ -175,7 +789,7 @@ impl MLogger {
             .lock()
             .map_err(|_| "pool lock failed!".to_string())?;
         for entry in pool.iter() {
-            println!("id:{} {} {}", entry.0, entry.2, entry.1);
+            println!("id:{} {} {}", entry.id, entry.verbosity, entry.message);
         }
         Ok(())
     }
@@ -190,14 +804,78 @@ impl MLogger {
             .pool
             .lock()
             .map_err(|_| "pool lock failed!".to_string())?;
-        let filtered_logs: Vec<(u32, String, Verbosity)> = pool
+        let filtered_logs: Vec<LogEntry> = pool
             .iter()
-            .filter(|log| log.2 == predicator)
+            .filter(|log| log.verbosity == predicator)
             .cloned()
             .collect();
         for entry in filtered_logs {
-            println!("{} {} {}", entry.0, entry.2, entry.1);
+            println!("{} {} {}", entry.id, entry.verbosity, entry.message);
         }
         Ok(())
     }
+    /// Prints all entries tagged with `tag` that also satisfy a verbosity filter.
+    ///
+    /// # Param
+    /// - `tag: &str`: The tag an entry must carry to be printed.
+    /// - `filter: Verbosity`: The predicate to limit the scope of the logs.
+    ///
+    /// Returns a `Result` with an error message as a `String` or `()` on success.
+    pub fn print_log_tagged(&self, tag: &str, filter: Verbosity) -> Result<(), String> {
+        let pool = self
+            .pool
+            .lock()
+            .map_err(|_| "pool lock failed!".to_string())?;
+        let filtered_logs: Vec<LogEntry> = pool
+            .iter()
+            .filter(|log| log.verbosity <= filter && log.tags.iter().any(|t| t == tag))
+            .cloned()
+            .collect();
+        for entry in filtered_logs {
+            println!("id:{} {} {}", entry.id, entry.verbosity, entry.message);
+        }
+        Ok(())
+    }
+}
+impl Drop for MLogger {
+    /// When this is the last handle to an async logger (see [`MLogger::init_async`]), signals
+    /// the worker thread to drain its queue and joins it so no queued logs are lost on shutdown.
+    fn drop(&mut self) {
+        let queue = match &self.queue {
+            Some(queue) => queue,
+            None => return,
+        };
+        let worker = match &self.worker {
+            Some(worker) => worker,
+            None => return,
+        };
+        if Arc::strong_count(worker) != 1 {
+            return;
+        }
+        queue.push_control(WorkerCommand::Shutdown);
+        if let Ok(mut guard) = worker.lock() {
+            if let Some(handle) = guard.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+/// Logs a formatted message through an [`MLogger`], checking the verbosity threshold before
+/// expanding `format_args!` so a suppressed message never pays for formatting.
+///
+/// ```ignore
+/// mlog!(logger, Verbosity::Info, "x={} y={}", x, y);
+/// ```
+#[macro_export]
+macro_rules! mlog {
+    ($logger:expr, $verbosity:expr, $($arg:tt)+) => {{
+        let logger = &$logger;
+        let verbosity = $verbosity;
+        if logger.accepts(verbosity) {
+            logger.log_fmt(format_args!($($arg)+), verbosity)
+        } else {
+            Ok(())
+        }
+    }};
 }